@@ -1,6 +1,7 @@
 use clap::{App, Arg};
 use lazy_static::lazy_static;
 use regex::Regex;
+use serde::Serialize;
 use std::{
     cmp::Reverse,
     collections::{BinaryHeap, HashMap},
@@ -9,7 +10,12 @@ use std::{
 };
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
-use hyperpolyglot::{get_language_breakdown, get_language_info, Detection, LanguageType};
+use hyperpolyglot::{
+    get_language_breakdown_from_git, get_language_breakdown_with_options, get_language_info,
+    get_line_counts, Detection, LanguageType, WalkOptions,
+};
+
+type FileEntry = (Detection, PathBuf, u64);
 
 struct CLIOptions {
     condensed_output: bool,
@@ -19,9 +25,32 @@ struct CLIOptions {
 fn main() {
     let matches = get_cli().get_matches();
     let path = matches.value_of("PATH").unwrap();
-    let breakdown = get_language_breakdown(path);
 
-    let mut language_count: Vec<(&'static str, Vec<(Detection, PathBuf)>)> = breakdown
+    if matches.is_present("git") && matches.is_present("lines") {
+        eprintln!(
+            "--lines is not supported with --git: line counts are read from disk, \
+             which may not reflect --rev's actual content"
+        );
+        std::process::exit(1);
+    }
+
+    let breakdown = if matches.is_present("git") {
+        match get_language_breakdown_from_git(path, matches.value_of("rev")) {
+            Ok(breakdown) => breakdown,
+            Err(err) => {
+                eprintln!("Failed to read git repository: {}", err);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        let walk_options = WalkOptions {
+            hidden: matches.is_present("hidden"),
+            no_ignore: matches.is_present("no-ignore"),
+        };
+        get_language_breakdown_with_options(path, &walk_options)
+    };
+
+    let mut language_count: Vec<(&'static str, Vec<FileEntry>)> = breakdown
         .into_iter()
         .filter(|(language_name, _)| {
             match get_language_info(language_name).map(|l| &l.language_type) {
@@ -30,8 +59,17 @@ fn main() {
             }
         })
         .collect();
-    language_count.sort_by(|(_, a), (_, b)| b.len().cmp(&a.len()));
-    print_language_split(&language_count);
+    language_count.sort_by(|(_, a), (_, b)| total_bytes(b).cmp(&total_bytes(a)));
+
+    if let Some(format) = matches.value_of("output") {
+        return print_structured_output(format, &language_count);
+    }
+
+    if matches.is_present("lines") {
+        print_line_breakdown(&language_count);
+    } else {
+        print_language_split(&language_count);
+    }
 
     let cli_options = CLIOptions {
         condensed_output: matches.is_present("condensed"),
@@ -86,32 +124,184 @@ fn get_cli<'a, 'b>() -> App<'a, 'b> {
                 "A regex that is used to filter the output for the file and streategy breakdown",
             ).takes_value(true),
         )
+        .arg(Arg::with_name("lines").short("l").long("lines").help(
+            "Prints code/comment/blank line counts per language instead of the byte-weighted percentage split",
+        ))
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Prints the full breakdown as structured data instead of colored text")
+                .takes_value(true)
+                .possible_values(&["json", "yaml"]),
+        )
+        .arg(
+            Arg::with_name("hidden")
+                .long("hidden")
+                .help("Includes hidden files and directories in the breakdown"),
+        )
+        .arg(Arg::with_name("no-ignore").long("no-ignore").help(
+            "Includes files ignored by .gitignore, global excludes, and .git/info/exclude",
+        ))
+        .arg(
+            Arg::with_name("git")
+                .long("git")
+                .help("Analyzes the files tracked by the git repository at PATH instead of the working directory"),
+        )
+        .arg(
+            Arg::with_name("rev")
+                .long("rev")
+                .help("With --git, the revision (branch, tag, or commit) to read the tree from; defaults to HEAD")
+                .takes_value(true)
+                .requires("git"),
+        )
+}
+
+fn total_bytes(files: &[FileEntry]) -> u64 {
+    files.iter().map(|(_, _, size)| size).sum()
+}
+
+/// `part` as a percentage of `total`, or `0.0` when `total` is zero (e.g.
+/// every matched file is empty) rather than dividing into `NaN`.
+fn percentage_of(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    ((part * 100) as f64) / (total as f64)
 }
 
-fn print_language_split(language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>) {
-    let total = language_counts
+fn print_language_split(language_counts: &Vec<(&'static str, Vec<FileEntry>)>) {
+    let total: u64 = language_counts
         .iter()
-        .fold(0, |acc, (_, files)| acc + files.len()) as f64;
+        .fold(0, |acc, (_, files)| acc + total_bytes(files));
+
+    let mut stdout = StandardStream::stdout(ColorChoice::Always);
     for (language, files) in language_counts.iter() {
-        let percentage = ((files.len() * 100) as f64) / total;
-        println!("{:.2}% {}", percentage, language);
+        let percentage = percentage_of(total_bytes(files), total);
+        let _ = write!(stdout, "{:.2}% ", percentage);
+        let _ = stdout.set_color(&language_color_spec(language));
+        let _ = writeln!(stdout, "{}", language);
+        let _ = stdout.set_color(&DEFAULT_COLOR);
+    }
+}
+
+/// Builds a `ColorSpec` for `language`, parsing its Linguist hex color into
+/// `Color::Rgb`. Falls back to the terminal default (no color set) when the
+/// language has no registered color, its hex string doesn't parse, or
+/// `$COLORTERM` doesn't advertise truecolor support.
+fn language_color_spec(language: &str) -> ColorSpec {
+    let mut spec = ColorSpec::new();
+    if terminal_supports_truecolor() {
+        if let Some(color) = get_language_info(language).and_then(|l| l.color) {
+            if let Some(rgb) = parse_hex_color(color) {
+                spec.set_fg(Some(rgb));
+            }
+        }
+    }
+    spec
+}
+
+/// Mirrors how most truecolor-aware terminal apps detect 24-bit color
+/// support: `$COLORTERM` is set to `truecolor` or `24bit` by terminals that
+/// support it (there's no portable terminfo capability for it).
+fn terminal_supports_truecolor() -> bool {
+    match std::env::var("COLORTERM") {
+        Ok(value) => value == "truecolor" || value == "24bit",
+        Err(_) => false,
+    }
+}
+
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::Rgb(r, g, b))
+}
+
+fn print_line_breakdown(language_counts: &Vec<(&'static str, Vec<FileEntry>)>) {
+    let breakdown: HashMap<&'static str, Vec<FileEntry>> = language_counts
+        .iter()
+        .map(|(language, files)| (*language, files.clone()))
+        .collect();
+    let mut line_counts: Vec<(&'static str, _)> = get_line_counts(&breakdown).into_iter().collect();
+    line_counts.sort_by(|(_, a), (_, b)| {
+        (b.code + b.comment + b.blank).cmp(&(a.code + a.comment + a.blank))
+    });
+
+    for (language, counts) in line_counts.iter() {
+        println!(
+            "{}: {} code, {} comment, {} blank",
+            language, counts.code, counts.comment, counts.blank
+        );
     }
 }
 
+#[derive(Serialize)]
+struct FileDetection {
+    path: PathBuf,
+    strategy: String,
+    bytes: u64,
+}
+
+#[derive(Serialize)]
+struct LanguageSummary {
+    language: &'static str,
+    file_count: usize,
+    percentage: f64,
+    files: Vec<FileDetection>,
+}
+
+fn build_summary(language_counts: &Vec<(&'static str, Vec<FileEntry>)>) -> Vec<LanguageSummary> {
+    let total: u64 = language_counts
+        .iter()
+        .fold(0, |acc, (_, files)| acc + total_bytes(files));
+
+    language_counts
+        .iter()
+        .map(|(language, files)| LanguageSummary {
+            language,
+            file_count: files.len(),
+            percentage: percentage_of(total_bytes(files), total),
+            files: files
+                .iter()
+                .map(|(detection, path, size)| FileDetection {
+                    path: strip_relative_parts(path).to_path_buf(),
+                    strategy: detection.variant(),
+                    bytes: *size,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn print_structured_output(format: &str, language_counts: &Vec<(&'static str, Vec<FileEntry>)>) {
+    let summary = build_summary(language_counts);
+    let rendered = match format {
+        "json" => serde_json::to_string_pretty(&summary).expect("failed to serialize summary"),
+        "yaml" => serde_yaml::to_string(&summary).expect("failed to serialize summary"),
+        _ => unreachable!("clap restricts --output to json or yaml"),
+    };
+    println!("{}", rendered);
+}
+
 fn print_file_breakdown(
-    language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
+    language_counts: &Vec<(&'static str, Vec<FileEntry>)>,
     options: &CLIOptions,
 ) -> Result<(), io::Error> {
     let mut stdout = StandardStream::stdout(ColorChoice::Always);
     for (language, breakdowns) in language_counts.iter() {
         if options.filter.is_match(language) {
-            stdout.set_color(&TITLE_COLOR)?;
+            stdout.set_color(&language_color_spec(language))?;
             write!(stdout, "{}", language)?;
 
             stdout.set_color(&DEFAULT_COLOR)?;
             writeln!(stdout, " ({})", breakdowns.len())?;
             if !options.condensed_output {
-                for (_, file) in breakdowns.iter() {
+                for (_, file, _) in breakdowns.iter() {
                     let path = strip_relative_parts(file);
                     writeln!(stdout, "{}", path.display())?;
                 }
@@ -123,12 +313,12 @@ fn print_file_breakdown(
 }
 
 fn print_strategy_breakdown(
-    language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
+    language_counts: &Vec<(&'static str, Vec<FileEntry>)>,
     options: &CLIOptions,
 ) -> Result<(), io::Error> {
     let mut strategy_breakdown = HashMap::new();
     for (language, files) in language_counts.into_iter() {
-        for (detection, file) in files.into_iter() {
+        for (detection, file, _) in files.into_iter() {
             let files = strategy_breakdown
                 .entry(detection.variant())
                 .or_insert(BinaryHeap::new());
@@ -154,7 +344,7 @@ fn print_strategy_breakdown(
                     let path = strip_relative_parts(file);
                     write!(stdout, "{}", path.display())?;
 
-                    stdout.set_color(&LANGUAGE_COLOR)?;
+                    stdout.set_color(&language_color_spec(language))?;
                     writeln!(stdout, " ({})", language)?;
                 }
                 writeln!(stdout, "")?;
@@ -179,9 +369,51 @@ lazy_static! {
         title_color
     };
     static ref DEFAULT_COLOR: ColorSpec = ColorSpec::default();
-    static ref LANGUAGE_COLOR: ColorSpec = {
-        let mut language_color = ColorSpec::new();
-        language_color.set_fg(Some(Color::Green));
-        language_color
-    };
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_valid_hex_color() {
+        assert_eq!(parse_hex_color("#dea584"), Some(Color::Rgb(0xde, 0xa5, 0x84)));
+        assert_eq!(parse_hex_color("dea584"), Some(Color::Rgb(0xde, 0xa5, 0x84)));
+    }
+
+    #[test]
+    fn rejects_malformed_hex_colors() {
+        assert_eq!(parse_hex_color("#abc"), None);
+        assert_eq!(parse_hex_color("#gggggg"), None);
+        assert_eq!(parse_hex_color(""), None);
+    }
+
+    #[test]
+    fn truecolor_is_gated_on_colorterm() {
+        std::env::remove_var("COLORTERM");
+        assert!(!terminal_supports_truecolor());
+
+        std::env::set_var("COLORTERM", "truecolor");
+        assert!(terminal_supports_truecolor());
+
+        std::env::set_var("COLORTERM", "24bit");
+        assert!(terminal_supports_truecolor());
+
+        std::env::set_var("COLORTERM", "256color");
+        assert!(!terminal_supports_truecolor());
+
+        std::env::remove_var("COLORTERM");
+    }
+
+    #[test]
+    fn percentage_of_handles_a_zero_total() {
+        assert_eq!(percentage_of(0, 0), 0.0);
+        assert_eq!(percentage_of(5, 0), 0.0);
+    }
+
+    #[test]
+    fn percentage_of_divides_normally() {
+        assert_eq!(percentage_of(25, 100), 25.0);
+        assert_eq!(percentage_of(1, 3), 100.0 / 3.0);
+    }
+}
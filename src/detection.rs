@@ -0,0 +1,89 @@
+use serde::Serialize;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// Records which heuristic matched a given file to its language.
+#[derive(Debug, Clone, Serialize)]
+pub enum Detection {
+    Filename(&'static str),
+    Extension(&'static str),
+    Shebang(&'static str),
+}
+
+impl Detection {
+    /// The name of the strategy that produced this detection, used to group
+    /// the `--strategies` breakdown.
+    pub fn variant(&self) -> String {
+        match self {
+            Detection::Filename(_) => "Filename".to_string(),
+            Detection::Extension(_) => "Extension".to_string(),
+            Detection::Shebang(_) => "Shebang".to_string(),
+        }
+    }
+}
+
+/// Tries to classify `path` by filename, then extension, then (for any
+/// file neither of those matched) by the interpreter named in a `#!`
+/// shebang line.
+/// There's no content-based heuristics pass yet, so a file that matches
+/// none of these (e.g. an extensionless, non-executable data file) is
+/// reported as undetected.
+///
+/// This reads `path` off disk for the shebang check, so it's only correct
+/// for files that actually exist there. Callers classifying a path that
+/// doesn't correspond to on-disk content (e.g. a blob from another git
+/// revision) should use `detect_from_name` instead.
+pub fn detect_from_path(path: &Path) -> Option<(&'static str, Detection)> {
+    if let Some(detection) = detect_from_name(path) {
+        return Some(detection);
+    }
+
+    if let Some(name) = detect_from_shebang(path) {
+        return Some((name, Detection::Shebang(name)));
+    }
+
+    None
+}
+
+/// Classifies `path` by filename, then extension, without touching disk.
+pub fn detect_from_name(path: &Path) -> Option<(&'static str, Detection)> {
+    if let Some(filename) = path.file_name().and_then(|f| f.to_str()) {
+        for (name, info) in crate::language::all_languages() {
+            if info.filenames.contains(&filename) {
+                return Some((name, Detection::Filename(name)));
+            }
+        }
+    }
+
+    if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
+        let dotted = format!(".{}", extension);
+        for (name, info) in crate::language::all_languages() {
+            if info.extensions.contains(&dotted.as_str()) {
+                return Some((name, Detection::Extension(name)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads the first line of `path` looking for a `#!` shebang, resolves an
+/// `env`-wrapped interpreter (`#!/usr/bin/env python3` -> `python3`), and
+/// matches it against each language's registered `interpreters`.
+fn detect_from_shebang(path: &Path) -> Option<&'static str> {
+    let file = fs::File::open(path).ok()?;
+    let mut first_line = String::new();
+    io::BufReader::new(file).read_line(&mut first_line).ok()?;
+
+    let interpreter_line = first_line.trim().strip_prefix("#!")?.trim();
+    let mut parts = interpreter_line.split_whitespace();
+    let mut interpreter = parts.next()?.rsplit('/').next()?;
+    if interpreter == "env" {
+        interpreter = parts.next()?;
+    }
+
+    crate::language::all_languages()
+        .find(|(_, info)| info.interpreters.contains(&interpreter))
+        .map(|(name, _)| *name)
+}
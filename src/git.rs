@@ -0,0 +1,64 @@
+use git2::{ObjectType, Repository, TreeWalkMode, TreeWalkResult};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::detection;
+use crate::LanguageBreakdown;
+
+/// Enumerates the files tracked by the git repository at `repo_path`, at
+/// `rev` if given (any revspec git understands — a branch, tag, or commit)
+/// or `HEAD` otherwise, and classifies each the same way
+/// `get_language_breakdown` does for a working directory. Untracked and
+/// gitignored files never show up here since they're never in the tree.
+///
+/// Classification only looks at each entry's path (filename/extension),
+/// not its blob content — `rev` may not be checked out anywhere on disk,
+/// so there's nothing else available to inspect it with. This means
+/// shebang-based detection doesn't apply here; see `detect_from_name`.
+pub fn get_language_breakdown_from_git<T: AsRef<Path>>(
+    repo_path: T,
+    rev: Option<&str>,
+) -> Result<LanguageBreakdown, git2::Error> {
+    let repo = Repository::discover(repo_path.as_ref())?;
+    let tree = match rev {
+        Some(rev) => repo.revparse_single(rev)?.peel_to_tree()?,
+        None => repo.head()?.peel_to_tree()?,
+    };
+
+    // Join each tree-relative path against the repo's working directory so
+    // the returned paths point somewhere meaningful; a bare repo has none,
+    // so fall back to whatever path the caller pointed us at.
+    let base = repo
+        .workdir()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| repo_path.as_ref().to_path_buf());
+
+    let mut breakdown: LanguageBreakdown = HashMap::new();
+    tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+        if entry.kind() != Some(ObjectType::Blob) {
+            return TreeWalkResult::Ok;
+        }
+        let name = match entry.name() {
+            Some(name) => name,
+            None => return TreeWalkResult::Ok,
+        };
+        let relative_path = PathBuf::from(format!("{}{}", root, name));
+
+        if let Some((language, detection)) = detection::detect_from_name(&relative_path) {
+            let size = entry
+                .to_object(&repo)
+                .ok()
+                .and_then(|object| object.into_blob().ok())
+                .map(|blob| blob.size() as u64)
+                .unwrap_or(0);
+            breakdown
+                .entry(language)
+                .or_default()
+                .push((detection, base.join(&relative_path), size));
+        }
+
+        TreeWalkResult::Ok
+    })?;
+
+    Ok(breakdown)
+}
@@ -0,0 +1,169 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// The broad category linguist buckets a language into. Only `Markup` and
+/// `Programming` languages are surfaced in the default breakdown; `Data` and
+/// `Prose` are detected but filtered out by the CLI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanguageType {
+    Data,
+    Markup,
+    Programming,
+    Prose,
+}
+
+/// Static metadata about a single language, keyed by its canonical linguist
+/// name in `get_language_info`.
+#[derive(Debug, Clone)]
+pub struct Language {
+    pub language_type: LanguageType,
+    pub color: Option<&'static str>,
+    pub extensions: Vec<&'static str>,
+    pub filenames: Vec<&'static str>,
+    pub interpreters: Vec<&'static str>,
+    /// The token that starts a line comment, e.g. `//` for Rust. `None` when
+    /// the language has no line-comment syntax.
+    pub line_comment: Option<&'static str>,
+    /// The `(start, end)` delimiters for a block comment, e.g. `("/*", "*/")`.
+    /// `None` when the language has no block-comment syntax.
+    pub block_comment: Option<(&'static str, &'static str)>,
+}
+
+lazy_static! {
+    static ref LANGUAGES: HashMap<&'static str, Language> = {
+        let mut languages = HashMap::new();
+        languages.insert(
+            "Rust",
+            Language {
+                language_type: LanguageType::Programming,
+                color: Some("#dea584"),
+                extensions: vec![".rs"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: Some("//"),
+                block_comment: Some(("/*", "*/")),
+            },
+        );
+        languages.insert(
+            "Python",
+            Language {
+                language_type: LanguageType::Programming,
+                color: Some("#3572a5"),
+                extensions: vec![".py"],
+                filenames: vec![],
+                interpreters: vec!["python", "python2", "python3"],
+                line_comment: Some("#"),
+                block_comment: None,
+            },
+        );
+        languages.insert(
+            "JavaScript",
+            Language {
+                language_type: LanguageType::Programming,
+                color: Some("#f1e05a"),
+                extensions: vec![".js"],
+                filenames: vec![],
+                interpreters: vec!["node"],
+                line_comment: Some("//"),
+                block_comment: Some(("/*", "*/")),
+            },
+        );
+        languages.insert(
+            "C",
+            Language {
+                language_type: LanguageType::Programming,
+                color: Some("#555555"),
+                extensions: vec![".c", ".h"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: Some("//"),
+                block_comment: Some(("/*", "*/")),
+            },
+        );
+        languages.insert(
+            "C++",
+            Language {
+                language_type: LanguageType::Programming,
+                color: Some("#f34b7d"),
+                extensions: vec![".cpp", ".cc", ".hpp"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: Some("//"),
+                block_comment: Some(("/*", "*/")),
+            },
+        );
+        languages.insert(
+            "Shell",
+            Language {
+                language_type: LanguageType::Programming,
+                color: Some("#89e051"),
+                extensions: vec![".sh", ".bash"],
+                filenames: vec![],
+                interpreters: vec!["sh", "bash", "zsh"],
+                line_comment: Some("#"),
+                block_comment: None,
+            },
+        );
+        languages.insert(
+            "HTML",
+            Language {
+                language_type: LanguageType::Markup,
+                color: Some("#e34c26"),
+                extensions: vec![".html", ".htm"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: None,
+                block_comment: Some(("<!--", "-->")),
+            },
+        );
+        languages.insert(
+            "Markdown",
+            Language {
+                language_type: LanguageType::Prose,
+                color: Some("#083fa1"),
+                extensions: vec![".md", ".markdown"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: None,
+                block_comment: None,
+            },
+        );
+        languages.insert(
+            "YAML",
+            Language {
+                language_type: LanguageType::Data,
+                color: Some("#cb171e"),
+                extensions: vec![".yml", ".yaml"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: Some("#"),
+                block_comment: None,
+            },
+        );
+        languages.insert(
+            "JSON",
+            Language {
+                language_type: LanguageType::Data,
+                color: Some("#292929"),
+                extensions: vec![".json"],
+                filenames: vec![],
+                interpreters: vec![],
+                line_comment: None,
+                block_comment: None,
+            },
+        );
+        languages
+    };
+}
+
+/// Looks up the static metadata linguist has for `language`, returning
+/// `None` for names we don't carry (or don't recognize).
+pub fn get_language_info(language: &str) -> Option<&'static Language> {
+    LANGUAGES.get(language)
+}
+
+/// Iterates every known `(name, Language)` pair, used by the detection
+/// strategies to scan for a filename/extension match.
+pub fn all_languages() -> impl Iterator<Item = (&'static &'static str, &'static Language)> {
+    LANGUAGES.iter()
+}
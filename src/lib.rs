@@ -0,0 +1,68 @@
+mod detection;
+mod git;
+mod language;
+mod loc;
+
+use ignore::WalkBuilder;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub use detection::Detection;
+pub use git::get_language_breakdown_from_git;
+pub use language::{get_language_info, Language, LanguageType};
+pub use loc::{get_line_counts, LineCounts};
+
+/// A language's canonical name mapped to every file detected as that
+/// language, alongside how it was detected and its size in bytes.
+pub type LanguageBreakdown = HashMap<&'static str, Vec<(Detection, PathBuf, u64)>>;
+
+/// Controls how `get_language_breakdown_with_options` traverses the
+/// directory tree.
+#[derive(Default)]
+pub struct WalkOptions {
+    /// Visit hidden files and directories (those starting with `.`).
+    pub hidden: bool,
+    /// Ignore `.gitignore`, global excludes, and `.git/info/exclude` rules.
+    pub no_ignore: bool,
+}
+
+/// Walks `path` and classifies every file it finds, grouping the results by
+/// the detected language's canonical name. Hidden files and anything
+/// `.gitignore`d are skipped; use `get_language_breakdown_with_options` to
+/// change that.
+pub fn get_language_breakdown<T: AsRef<Path>>(path: T) -> LanguageBreakdown {
+    get_language_breakdown_with_options(path, &WalkOptions::default())
+}
+
+/// Same as `get_language_breakdown`, but lets the caller opt into hidden
+/// files and/or untracked, gitignored files via `options`.
+pub fn get_language_breakdown_with_options<T: AsRef<Path>>(
+    path: T,
+    options: &WalkOptions,
+) -> LanguageBreakdown {
+    let mut breakdown: LanguageBreakdown = HashMap::new();
+
+    let walker = WalkBuilder::new(path.as_ref())
+        .hidden(!options.hidden)
+        .ignore(!options.no_ignore)
+        .git_ignore(!options.no_ignore)
+        .git_global(!options.no_ignore)
+        .git_exclude(!options.no_ignore)
+        .build();
+
+    for entry in walker.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        if let Some((language, detection)) = detection::detect_from_path(path) {
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            breakdown
+                .entry(language)
+                .or_default()
+                .push((detection, path.to_path_buf(), size));
+        }
+    }
+
+    breakdown
+}
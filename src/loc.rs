@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::language::get_language_info;
+use crate::LanguageBreakdown;
+
+/// Code/comment/blank line totals for a file or an aggregated set of files.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LineCounts {
+    pub code: usize,
+    pub comment: usize,
+    pub blank: usize,
+}
+
+impl LineCounts {
+    fn add(&mut self, other: LineCounts) {
+        self.code += other.code;
+        self.comment += other.comment;
+        self.blank += other.blank;
+    }
+}
+
+/// Walks every file in `breakdown` and aggregates its code/comment/blank
+/// line counts per language. Mirrors tokei's approach of tracking block
+/// comment nesting depth while scanning line by line.
+///
+/// This is a simple single-pass scan: it does not understand string
+/// literals, so a comment token that appears inside a string (e.g.
+/// `"http://"`) is misclassified. Languages with no registered comment
+/// syntax (see `Language::line_comment`/`block_comment`) have every
+/// non-blank line counted as code.
+pub fn get_line_counts(breakdown: &LanguageBreakdown) -> HashMap<&'static str, LineCounts> {
+    let mut totals: HashMap<&'static str, LineCounts> = HashMap::new();
+    for (language, files) in breakdown.iter() {
+        let mut language_totals = LineCounts::default();
+        for (_, path, _) in files.iter() {
+            if let Ok(counts) = count_file(path, language) {
+                language_totals.add(counts);
+            }
+        }
+        totals.insert(language, language_totals);
+    }
+    totals
+}
+
+fn count_file(path: &PathBuf, language: &str) -> std::io::Result<LineCounts> {
+    let contents = fs::read_to_string(path)?;
+    let info = get_language_info(language);
+    let line_comment = info.and_then(|l| l.line_comment);
+    let block_comment = info.and_then(|l| l.block_comment);
+
+    let mut counts = LineCounts::default();
+    let mut in_block: usize = 0;
+    for line in contents.lines() {
+        let kind = classify_line(line, &mut in_block, line_comment, block_comment);
+        match kind {
+            LineKind::Blank => counts.blank += 1,
+            LineKind::Comment => counts.comment += 1,
+            LineKind::Code => counts.code += 1,
+        }
+    }
+    Ok(counts)
+}
+
+enum LineKind {
+    Blank,
+    Comment,
+    Code,
+}
+
+/// Classifies a single line, advancing `in_block` (the current block-comment
+/// nesting depth) as it goes.
+fn classify_line(
+    line: &str,
+    in_block: &mut usize,
+    line_comment: Option<&str>,
+    block_comment: Option<(&str, &str)>,
+) -> LineKind {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        return LineKind::Blank;
+    }
+
+    let mut cursor = trimmed;
+    let mut saw_code = false;
+
+    loop {
+        if *in_block > 0 {
+            let (start, end) = block_comment.expect("in_block > 0 implies block comments exist");
+            let next_start = cursor.find(start);
+            let next_end = cursor.find(end);
+            match (next_start, next_end) {
+                (Some(s), Some(e)) if s < e => {
+                    // A nested block comment opens before this one closes.
+                    *in_block += 1;
+                    cursor = &cursor[s + start.len()..];
+                }
+                (Some(s), None) => {
+                    // A nested block comment opens with no closer on this line.
+                    *in_block += 1;
+                    cursor = &cursor[s + start.len()..];
+                }
+                (_, Some(e)) => {
+                    *in_block -= 1;
+                    cursor = &cursor[e + end.len()..];
+                }
+                (None, None) => return if saw_code { LineKind::Code } else { LineKind::Comment },
+            }
+        } else if let Some(token) = line_comment.filter(|t| cursor.starts_with(t)) {
+            let _ = token;
+            return if saw_code { LineKind::Code } else { LineKind::Comment };
+        } else if let Some((start, _)) = block_comment {
+            match cursor.find(start) {
+                Some(idx) => {
+                    if !cursor[..idx].trim().is_empty() {
+                        saw_code = true;
+                    }
+                    *in_block += 1;
+                    cursor = &cursor[idx + start.len()..];
+                }
+                None => return LineKind::Code,
+            }
+        } else {
+            return LineKind::Code;
+        }
+
+        if cursor.trim().is_empty() {
+            return if *in_block > 0 || !saw_code {
+                if saw_code {
+                    LineKind::Code
+                } else {
+                    LineKind::Comment
+                }
+            } else {
+                LineKind::Code
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST_BLOCK: Option<(&str, &str)> = Some(("/*", "*/"));
+
+    fn kind(line: &str, in_block: &mut usize) -> LineKind {
+        classify_line(line, in_block, Some("//"), RUST_BLOCK)
+    }
+
+    #[test]
+    fn nested_block_comment_on_one_line_is_all_comment() {
+        let mut in_block = 0;
+        let result = kind("/* outer /* inner */ still comment */", &mut in_block);
+        assert!(matches!(result, LineKind::Comment));
+        assert_eq!(in_block, 0);
+    }
+
+    #[test]
+    fn code_after_a_nested_block_comment_closes_is_code() {
+        let mut in_block = 0;
+        let result = kind("/* outer /* inner */ still comment */ code()", &mut in_block);
+        assert!(matches!(result, LineKind::Code));
+        assert_eq!(in_block, 0);
+    }
+
+    #[test]
+    fn unclosed_nested_block_comment_carries_depth_to_next_line() {
+        let mut in_block = 0;
+        let first = kind("/* outer /* inner", &mut in_block);
+        assert!(matches!(first, LineKind::Comment));
+        assert_eq!(in_block, 2);
+
+        let second = kind("*/ still not done", &mut in_block);
+        assert!(matches!(second, LineKind::Comment));
+        assert_eq!(in_block, 1);
+    }
+}